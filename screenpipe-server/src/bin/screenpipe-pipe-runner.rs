@@ -1,14 +1,190 @@
 use clap::Parser;
+use futures::future::select_all;
 use log::{error, info, warn, LevelFilter};
 use reqwest;
 #[cfg(feature = "pipes")]
 use screenpipe_core::run_js;
 use screenpipe_server::Cli;
+use sha2::{Digest, Sha256};
 use std::io::Write;
-use std::path::Path;
-use tempfile::NamedTempFile;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Semaphore;
 use url::Url;
 
+/// Number of attempts a request gets before `fetch_with_retry` gives up.
+const MAX_ATTEMPTS: u32 = 4;
+
+/// Error raised when a request completes but returns a non-2xx status,
+/// carrying enough detail (status, redirect target) to act on instead of
+/// silently reading the error page as if it were the pipe's content.
+#[derive(Debug)]
+struct HttpStatusError {
+    url: String,
+    status: reqwest::StatusCode,
+    location: Option<String>,
+}
+
+impl std::fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GET {} returned {}", self.url, self.status)?;
+        if let Some(location) = &self.location {
+            write!(f, " (redirects to {})", location)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
+
+/// Build the shared client used for every pipe download: bounded timeout,
+/// no implicit TLS backend choice beyond whichever cargo feature is enabled.
+/// Redirects are handled by `fetch_with_retry` itself rather than reqwest's
+/// default policy, so a redirect's `Location` is never silently consumed
+/// before an error has a chance to report it.
+fn build_http_client(timeout: Duration) -> Result<reqwest::Client, Box<dyn std::error::Error>> {
+    let builder = reqwest::Client::builder()
+        .connect_timeout(timeout)
+        .timeout(timeout)
+        .redirect(reqwest::redirect::Policy::none());
+
+    #[cfg(feature = "native-tls")]
+    let builder = builder.use_native_tls();
+    #[cfg(feature = "rustls-tls")]
+    let builder = builder.use_rustls_tls();
+
+    Ok(builder.build()?)
+}
+
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+}
+
+fn retry_delay(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    retry_after.unwrap_or_else(|| Duration::from_millis(250 * 2u64.pow(attempt - 1)))
+}
+
+/// Parse a `Retry-After` header value, which per RFC 9110 is either a
+/// number of seconds or an HTTP-date naming the moment to retry at.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let when = chrono::DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&chrono::Utc);
+    (when - chrono::Utc::now()).to_std().ok()
+}
+
+/// Upper bound on redirect hops `fetch_with_retry` will follow manually
+/// before giving up. The shared client disables reqwest's built-in redirect
+/// policy so that a non-2xx `Location` is always the one the server just
+/// sent, not silently swallowed by an already-followed hop.
+const MAX_REDIRECTS: u32 = 10;
+
+/// GET `url` with exponential backoff on connection failures, 5xx, and 429
+/// (honoring `Retry-After` when present). Non-retryable statuses surface as
+/// a structured [`HttpStatusError`] instead of being read as a body.
+/// 3xx responses are followed manually (up to [`MAX_REDIRECTS`] hops) so
+/// that an unresolvable redirect still reports its `Location`.
+/// `extra_headers` lets callers attach conditional-request headers
+/// (`If-None-Match`, `If-Modified-Since`); a resulting 304 is returned as-is
+/// rather than treated as an error.
+async fn fetch_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    extra_headers: &[(reqwest::header::HeaderName, String)],
+) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+    let mut current_url = url.to_string();
+
+    for _hop in 0..=MAX_REDIRECTS {
+        for attempt in 1..=MAX_ATTEMPTS {
+            let mut request = client.get(&current_url);
+            for (name, value) in extra_headers {
+                request = request.header(name, value.as_str());
+            }
+            let sent = request.send().await;
+            match sent {
+                Ok(response) => {
+                    let status = response.status();
+                    if status.is_success() || status == reqwest::StatusCode::NOT_MODIFIED {
+                        return Ok(response);
+                    }
+
+                    let location = response
+                        .headers()
+                        .get(reqwest::header::LOCATION)
+                        .and_then(|v| v.to_str().ok())
+                        .map(str::to_string);
+
+                    if status.is_redirection() {
+                        match location
+                            .as_deref()
+                            .and_then(|loc| resolve_redirect_url(&current_url, loc))
+                        {
+                            Some(next_url) => {
+                                info!("GET {} redirected ({}) to {}", current_url, status, next_url);
+                                current_url = next_url;
+                            }
+                            None => {
+                                return Err(Box::new(HttpStatusError {
+                                    url: current_url,
+                                    status,
+                                    location,
+                                }));
+                            }
+                        }
+                        break;
+                    }
+
+                    if !is_transient_status(status) || attempt == MAX_ATTEMPTS {
+                        return Err(Box::new(HttpStatusError {
+                            url: current_url,
+                            status,
+                            location,
+                        }));
+                    }
+
+                    let retry_after = response
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(parse_retry_after);
+                    let delay = retry_delay(attempt, retry_after);
+                    warn!(
+                        "GET {} returned {}, retrying in {:?} (attempt {}/{})",
+                        current_url, status, delay, attempt, MAX_ATTEMPTS
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) if attempt < MAX_ATTEMPTS && (e.is_timeout() || e.is_connect()) => {
+                    let delay = retry_delay(attempt, None);
+                    warn!(
+                        "GET {} failed: {}, retrying in {:?} (attempt {}/{})",
+                        current_url, e, delay, attempt, MAX_ATTEMPTS
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+    Err(format!("GET {} exceeded {} redirects", url, MAX_REDIRECTS).into())
+}
+
+/// Resolve a `Location` header against the URL it was received in response
+/// to; `Location` may be relative (a path) or absolute.
+fn resolve_redirect_url(current_url: &str, location: &str) -> Option<String> {
+    Url::parse(current_url)
+        .ok()?
+        .join(location)
+        .ok()
+        .map(|u| u.to_string())
+}
+
 #[cfg(feature = "pipes")]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -26,137 +202,775 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .format_timestamp_secs()
         .init();
 
-    warn!("Warning: only 1 pipe is supported right now. This will change in the future.");
+    let max_concurrent_pipes = cli
+        .max_concurrent_pipes
+        .unwrap_or_else(num_cpus::get)
+        .max(1);
+    info!(
+        "Running {} pipe(s) with up to {} concurrently",
+        cli.pipe.len(),
+        max_concurrent_pipes
+    );
 
-    let pipe_input = &cli.pipe[0];
+    let download_timeout = Duration::from_secs(cli.download_timeout.unwrap_or(30));
+    let http_client = build_http_client(download_timeout)?;
+
+    // Resolve every input (download or canonicalize) in parallel before we
+    // start spending semaphore permits, so N slow downloads don't serialize.
+    let allowed_git_hosts = cli.allowed_git_hosts.clone().unwrap_or_default();
+    let cache_dir = resolve_cache_dir(&cli.pipe_cache_dir)?;
+    let resolutions = futures::future::join_all(cli.pipe.iter().map(|pipe_input| {
+        let http_client = &http_client;
+        let allowed_git_hosts = &allowed_git_hosts;
+        let cache_dir = &cache_dir;
+        async move {
+            let result = resolve_pipe_input(http_client, pipe_input, allowed_git_hosts, cache_dir).await;
+            (pipe_input.clone(), result)
+        }
+    }))
+    .await;
+
+    // A pipe that never resolves is a failed pipe, same as one that runs and
+    // errors; track it here so the exit code reflects it even when other
+    // pipes resolved fine.
+    let mut any_failed = false;
+    let mut resolved = Vec::with_capacity(cli.pipe.len());
+    for (pipe_input, result) in resolutions {
+        match result {
+            Ok(resolved_pipe) => resolved.push((pipe_input, resolved_pipe)),
+            Err(e) => {
+                error!("Failed to resolve pipe '{}': {}", pipe_input, e);
+                any_failed = true;
+            }
+        }
+    }
+
+    if resolved.is_empty() {
+        error!("No pipe could be resolved, nothing to run.");
+        std::process::exit(1);
+    }
+
+    let semaphore = Arc::new(Semaphore::new(max_concurrent_pipes));
+
+    // Pipes with a declared `schedule` run forever instead of once; split
+    // them out so the one-shot batch can finish and exit normally.
+    let mut one_shot = Vec::new();
+    let mut scheduled = Vec::new();
+    for (pipe_input, resolved_pipe) in resolved {
+        let permissions = resolved_pipe.manifest.permissions;
+        match resolved_pipe.manifest.schedule {
+            Some(schedule) => {
+                scheduled.push((pipe_input, resolved_pipe.entry, schedule, permissions))
+            }
+            None => one_shot.push((pipe_input, resolved_pipe.entry, permissions)),
+        }
+    }
+
+    let mut pending: Vec<_> = one_shot
+        .into_iter()
+        .map(|(pipe_input, entry, permissions)| {
+            let semaphore = semaphore.clone();
+            Box::pin(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore closed");
+                info!("Starting pipe: {} ({:?})", pipe_input, entry);
+                let result = run_js(
+                    &entry.to_string_lossy(),
+                    &permissions.network,
+                    &permissions.filesystem,
+                )
+                .await;
+                (pipe_input, result)
+            })
+        })
+        .collect();
+
+    while !pending.is_empty() {
+        let (outcome, _index, remaining) = select_all(pending).await;
+        pending = remaining;
+        let (pipe_input, result) = outcome;
+        match result {
+            Ok(_) => info!("Pipe '{}' completed successfully", pipe_input),
+            Err(error) => {
+                error!("Pipe '{}' failed: {}", pipe_input, error);
+                any_failed = true;
+            }
+        }
+    }
+
+    if scheduled.is_empty() {
+        if any_failed {
+            error!("One or more pipes failed");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    info!(
+        "Keeping process alive to run {} scheduled pipe(s)",
+        scheduled.len()
+    );
+    let scheduled_tasks = scheduled.into_iter().map(
+        |(pipe_input, entry, schedule_expr, permissions)| {
+            tokio::spawn(run_on_schedule(
+                pipe_input,
+                entry,
+                schedule_expr,
+                permissions,
+                semaphore.clone(),
+            ))
+        },
+    );
+    futures::future::join_all(scheduled_tasks).await;
+
+    if any_failed {
+        error!("One or more pipes failed");
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// The `cron` crate parses 6/7-field, seconds-first expressions, but pipe
+/// manifests are documented as taking a standard 5-field crontab (minute
+/// first, no seconds). Prepend a `0` seconds field when given 5 fields so
+/// ordinary crontab syntax like `*/5 * * * *` keeps working; anything else
+/// (6/7 fields, or malformed input) is passed through for `cron` to parse
+/// or reject as-is.
+fn normalize_cron_expr(expr: &str) -> String {
+    if expr.split_whitespace().count() == 5 {
+        format!("0 {}", expr)
+    } else {
+        expr.to_string()
+    }
+}
+
+/// Keep re-running `entry` on `schedule_expr`'s cron cadence, forever,
+/// enforcing whatever network/filesystem permissions the manifest declared.
+async fn run_on_schedule(
+    pipe_input: String,
+    entry: PathBuf,
+    schedule_expr: String,
+    permissions: Permissions,
+    semaphore: Arc<Semaphore>,
+) {
+    let schedule = match cron::Schedule::from_str(&normalize_cron_expr(&schedule_expr)) {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            error!(
+                "Invalid cron schedule '{}' for pipe '{}': {}",
+                schedule_expr, pipe_input, e
+            );
+            return;
+        }
+    };
+
+    loop {
+        let now = chrono::Utc::now();
+        let Some(next_run) = schedule.after(&now).next() else {
+            error!(
+                "Cron schedule '{}' for pipe '{}' has no future occurrences",
+                schedule_expr, pipe_input
+            );
+            return;
+        };
+        let wait = (next_run - now).to_std().unwrap_or(Duration::ZERO);
+        info!(
+            "Pipe '{}' next scheduled run at {} (in {:?})",
+            pipe_input, next_run, wait
+        );
+        tokio::time::sleep(wait).await;
+
+        let _permit = semaphore.clone().acquire_owned().await.expect("semaphore closed");
+        info!("Running scheduled pipe: {}", pipe_input);
+        match run_js(
+            &entry.to_string_lossy(),
+            &permissions.network,
+            &permissions.filesystem,
+        )
+        .await
+        {
+            Ok(_) => info!("Scheduled pipe '{}' completed successfully", pipe_input),
+            Err(e) => error!("Scheduled pipe '{}' failed: {}", pipe_input, e),
+        }
+    }
+}
+
+async fn resolve_pipe_input(
+    client: &reqwest::Client,
+    pipe_input: &str,
+    allowed_hosts: &[String],
+    cache_dir: &Path,
+) -> Result<ResolvedPipe, Box<dyn std::error::Error>> {
     info!("Attempting to process pipe input: {}", pipe_input);
 
-    let path_to_main_module = match Url::parse(pipe_input) {
+    match Url::parse(pipe_input) {
         Ok(_) => {
             info!("Input appears to be a URL. Attempting to download...");
-            match download_pipe(pipe_input).await {
-                Ok(path) => path,
-                Err(e) => {
-                    error!("Failed to download pipe: {}", e);
-                    return Err(e);
-                }
-            }
+            download_pipe(client, pipe_input, allowed_hosts, cache_dir).await
         }
         Err(_) => {
             info!("Input appears to be a local path. Attempting to canonicalize...");
-            match Path::new(pipe_input).canonicalize() {
-                Ok(path) => path,
-                Err(e) => {
-                    error!("Failed to canonicalize path: {}", e);
-                    return Err(e.into());
+            let entry = Path::new(pipe_input).canonicalize()?;
+            let manifest = load_sidecar_manifest(&entry);
+            Ok(ResolvedPipe { entry, manifest })
+        }
+    }
+}
+
+/// Rewrite a GitHub file page (`blob`) or directory page (`tree`) URL into
+/// its `raw.githubusercontent.com` equivalent.
+fn github_raw_url(path_segments: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    if path_segments.len() < 4 {
+        return Err(
+            "GitHub URL does not look like a file page (expected owner/repo/blob-or-tree/branch/path)"
+                .into(),
+        );
+    }
+    let (owner, repo, kind, branch) =
+        (path_segments[0], path_segments[1], path_segments[2], path_segments[3]);
+    if kind != "blob" && kind != "tree" {
+        return Err(format!("unsupported GitHub URL shape: expected blob/tree, got '{}'", kind).into());
+    }
+    let raw_path = path_segments[4..].join("/");
+    Ok(format!(
+        "https://raw.githubusercontent.com/{}/{}/{}/{}",
+        owner, repo, branch, raw_path
+    ))
+}
+
+/// Rewrite a `gist.github.com/<owner>/<id>` page into its raw content URL.
+fn gist_raw_url(path_segments: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    if path_segments.len() < 2 {
+        return Err("Gist URL does not look like owner/id".into());
+    }
+    let (owner, id) = (path_segments[0], path_segments[1]);
+    Ok(format!("https://gist.githubusercontent.com/{}/{}/raw", owner, id))
+}
+
+/// Rewrite a GitLab `.../-/blob/<branch>/<path>` page into `.../-/raw/...`.
+fn gitlab_raw_url(host: &str, path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    if !path.contains("/-/blob/") {
+        return Err("GitLab URL does not look like a blob page (expected .../-/blob/branch/path)".into());
+    }
+    Ok(format!("https://{}{}", host, path.replacen("/-/blob/", "/-/raw/", 1)))
+}
+
+/// Rewrite a Bitbucket `.../src/<branch>/<path>` page into `.../raw/...`.
+fn bitbucket_raw_url(host: &str, path: &str) -> Result<String, Box<dyn std::error::Error>> {
+    if !path.contains("/src/") {
+        return Err("Bitbucket URL does not look like a source page (expected .../src/branch/path)".into());
+    }
+    Ok(format!("https://{}{}", host, path.replacen("/src/", "/raw/", 1)))
+}
+
+/// Resolve a human-facing git host page URL to its raw-content URL.
+/// Recognizes GitHub (blob + tree), GitHub Gist, GitLab, and Bitbucket by
+/// their public hostnames; `allowed_hosts` lets self-hosted instances of
+/// GitLab/Bitbucket opt in by matching their URL shape instead. Anything
+/// else (including URLs that are already raw) passes through unchanged.
+fn get_raw_url(url: &str, allowed_hosts: &[String]) -> Result<String, Box<dyn std::error::Error>> {
+    info!("Resolving raw content URL for: {}", url);
+    let parsed_url = Url::parse(url)?;
+    let host = match parsed_url.host_str() {
+        Some(host) => host,
+        None => return Ok(url.to_string()),
+    };
+    let path = parsed_url.path();
+    let is_allowed_self_hosted = allowed_hosts.iter().any(|h| h == host);
+    let already_raw = (host == "gitlab.com" && path.contains("/-/raw/"))
+        || (host == "bitbucket.org" && path.split('/').nth(3) == Some("raw"));
+
+    let raw_url = if already_raw {
+        info!("URL is already a raw content URL, using as-is: {}", url);
+        url.to_string()
+    } else if host == "github.com" {
+        let path_segments: Vec<&str> = parsed_url.path_segments().map(Iterator::collect).unwrap_or_default();
+        github_raw_url(&path_segments)?
+    } else if host == "gist.github.com" {
+        let path_segments: Vec<&str> = parsed_url.path_segments().map(Iterator::collect).unwrap_or_default();
+        gist_raw_url(&path_segments)?
+    } else if host == "gitlab.com" || (is_allowed_self_hosted && path.contains("/-/blob/")) {
+        gitlab_raw_url(host, path)?
+    } else if host == "bitbucket.org" || (is_allowed_self_hosted && path.contains("/src/")) {
+        bitbucket_raw_url(host, path)?
+    } else {
+        info!("URL is not a recognized git host page, using as-is: {}", url);
+        url.to_string()
+    };
+
+    info!("Resolved raw content URL: {}", raw_url);
+    Ok(raw_url)
+}
+
+/// A single step of a pipe installation, as described by a `pipe.json` manifest.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Step {
+    DownloadFile { url: String, dest: PathBuf },
+    ExtractFile { file: PathBuf, dest: PathBuf },
+    ExecuteCommand { cmd: String, args: Vec<String> },
+    RunJs { entry: PathBuf },
+}
+
+/// An ordered list of install steps; the shared working directory they run
+/// in is passed to [`Pipeline::run`].
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct Pipeline {
+    #[serde(default)]
+    steps: Vec<Step>,
+}
+
+impl Pipeline {
+    /// Run every step in order against `work_dir`, returning the path to the
+    /// entry module declared by the `RunJs` step, if any.
+    async fn run(
+        &self,
+        client: &reqwest::Client,
+        work_dir: &Path,
+    ) -> Result<Option<PathBuf>, Box<dyn std::error::Error>> {
+        let mut entry_point = None;
+        for step in &self.steps {
+            match step {
+                Step::DownloadFile { url, dest } => {
+                    let dest = work_dir.join(dest);
+                    if dest.exists() {
+                        info!("Skipping download, already present: {:?}", dest);
+                    } else {
+                        download_file(client, url, &dest).await?;
+                    }
+                }
+                Step::ExtractFile { file, dest } => {
+                    extract_archive(&work_dir.join(file), &work_dir.join(dest))?;
                 }
+                Step::ExecuteCommand { cmd, args } => {
+                    info!("Running install command: {} {:?}", cmd, args);
+                    let status = std::process::Command::new(cmd)
+                        .args(args)
+                        .current_dir(work_dir)
+                        .status()?;
+                    if !status.success() {
+                        return Err(format!("command '{}' exited with {}", cmd, status).into());
+                    }
+                }
+                Step::RunJs { entry } => entry_point = Some(work_dir.join(entry)),
             }
         }
-    };
+        Ok(entry_point)
+    }
+}
 
-    info!("Path to main module: {:?}", path_to_main_module);
+/// A pipe's declared permission set: the network hosts and filesystem paths
+/// it is allowed to touch. `Permissions` itself is local to this binary
+/// (`screenpipe_core`, where `run_js` lives, can't name a type defined by a
+/// crate that depends on it), so its fields are passed to `run_js`
+/// individually rather than the struct as a whole.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct Permissions {
+    #[serde(default)]
+    network: Vec<String>,
+    #[serde(default)]
+    filesystem: Vec<PathBuf>,
+}
+
+/// Manifest shape of a `pipe.json`/`pipe.toml`/directory-style pipe: a list
+/// of install `steps`, an `entry` module, or both (the entry runs after the
+/// steps), plus an optional cron `schedule` and `permissions`.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+struct PipeManifest {
+    #[serde(default)]
+    entry: Option<PathBuf>,
+    #[serde(default)]
+    steps: Vec<Step>,
+    #[serde(default)]
+    schedule: Option<String>,
+    #[serde(default)]
+    permissions: Permissions,
+}
 
-    match run_js(&path_to_main_module.to_string_lossy()).await {
-        Ok(_) => info!("JS execution completed successfully"),
-        Err(error) => {
-            error!("Error during JS execution: {}", error);
-            return Err(error.into());
+impl From<PipeManifest> for Pipeline {
+    fn from(manifest: PipeManifest) -> Self {
+        let mut steps = manifest.steps;
+        if let Some(entry) = manifest.entry {
+            steps.push(Step::RunJs { entry });
         }
+        Pipeline { steps }
     }
+}
+
+/// The scheduling and permission metadata carried alongside a resolved
+/// entry module, independent of how that entry was installed.
+#[derive(Debug, Clone, Default)]
+struct ManifestInfo {
+    schedule: Option<String>,
+    permissions: Permissions,
+}
 
+impl From<&PipeManifest> for ManifestInfo {
+    fn from(manifest: &PipeManifest) -> Self {
+        ManifestInfo {
+            schedule: manifest.schedule.clone(),
+            permissions: manifest.permissions.clone(),
+        }
+    }
+}
+
+/// A pipe input fully resolved to a runnable entry module plus whatever
+/// schedule/permissions its manifest (if any) declared.
+#[derive(Debug, Clone)]
+struct ResolvedPipe {
+    entry: PathBuf,
+    manifest: ManifestInfo,
+}
+
+/// Look for a `pipe.json`/`pipe.toml` next to a local entry module. Bare
+/// scripts with no manifest just run once with no declared permissions.
+fn load_sidecar_manifest(entry: &Path) -> ManifestInfo {
+    let Some(dir) = entry.parent() else {
+        return ManifestInfo::default();
+    };
+
+    for name in ["pipe.json", "pipe.toml"] {
+        let manifest_path = dir.join(name);
+        let Ok(raw) = std::fs::read_to_string(&manifest_path) else {
+            continue;
+        };
+        match parse_manifest_str(name, &raw) {
+            Ok(manifest) => {
+                info!("Loaded pipe manifest: {:?}", manifest_path);
+                return ManifestInfo::from(&manifest);
+            }
+            Err(e) => warn!("Failed to parse pipe manifest {:?}: {}", manifest_path, e),
+        }
+    }
+
+    ManifestInfo::default()
+}
+
+fn is_archive_url(url: &str) -> bool {
+    url.ends_with(".tar.gz") || url.ends_with(".tgz") || url.ends_with(".zip")
+}
+
+async fn download_file(
+    client: &reqwest::Client,
+    url: &str,
+    dest: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Downloading file: {} -> {:?}", url, dest);
+    let bytes = fetch_with_retry(client, url, &[]).await?.bytes().await?;
+    if let Some(parent) = dest.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(dest, &bytes)?;
     Ok(())
 }
 
-fn get_raw_github_url(url: &str) -> Result<String, Box<dyn std::error::Error>> {
-    info!("Attempting to get raw GitHub URL for: {}", url);
-    let parsed_url = Url::parse(url)?;
-    if parsed_url.host_str() == Some("github.com") {
-        let path_segments: Vec<&str> = parsed_url.path_segments().unwrap().collect();
-        if path_segments.len() >= 3 {
-            let (owner, repo, _, branch) = (
-                path_segments[0],
-                path_segments[1],
-                path_segments[2],
-                path_segments[3],
-            );
-            let raw_path = path_segments[4..].join("/");
-            let raw_url = format!(
-                "https://raw.githubusercontent.com/{}/{}/{}/{}",
-                owner, repo, branch, raw_path
-            );
-            info!("Converted to raw GitHub URL: {}", raw_url);
-            return Ok(raw_url);
+fn extract_archive(file: &Path, dest: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Extracting {:?} -> {:?}", file, dest);
+    std::fs::create_dir_all(dest)?;
+    match file.extension().and_then(|ext| ext.to_str()) {
+        Some("zip") => {
+            let archive_file = std::fs::File::open(file)?;
+            let mut archive = zip::ZipArchive::new(archive_file)?;
+            archive.extract(dest)?;
+        }
+        _ => {
+            // Treat anything else (`.tar.gz`, `.tgz`) as a gzip-compressed tarball.
+            let archive_file = std::fs::File::open(file)?;
+            let tar = flate2::read::GzDecoder::new(archive_file);
+            tar::Archive::new(tar).unpack(dest)?;
+        }
+    }
+    Ok(())
+}
+
+/// Parse a manifest's raw content as JSON or TOML, picked by the filename
+/// it was loaded from.
+fn parse_manifest_str(file_name: &str, raw: &str) -> Result<PipeManifest, Box<dyn std::error::Error>> {
+    if file_name.ends_with(".toml") {
+        toml::from_str(raw).map_err(Into::into)
+    } else {
+        serde_json::from_str(raw).map_err(Into::into)
+    }
+}
+
+/// Look for a pipe's install manifest in an extracted archive: at the root,
+/// or one directory down (tarballs conventionally wrap their contents in a
+/// single top-level folder).
+fn find_extracted_manifest(work_dir: &Path) -> Option<PathBuf> {
+    for name in ["pipe.json", "pipe.toml"] {
+        let candidate = work_dir.join(name);
+        if candidate.is_file() {
+            return Some(candidate);
         }
     }
-    info!("URL is not a GitHub URL, returning as-is");
-    Ok(url.to_string())
+    for entry in std::fs::read_dir(work_dir).ok()?.flatten() {
+        let dir = entry.path();
+        if !dir.is_dir() {
+            continue;
+        }
+        for name in ["pipe.json", "pipe.toml"] {
+            let candidate = dir.join(name);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+    }
+    None
 }
 
-async fn download_pipe(url: &str) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+/// Fetch a remote pipe's install manifest, trying `pipe.json` then
+/// `pipe.toml` when `url` doesn't already name one directly — mirrors
+/// `load_sidecar_manifest`'s local lookup.
+async fn fetch_manifest(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<PipeManifest, Box<dyn std::error::Error>> {
+    let candidates: Vec<String> = if url.ends_with("pipe.json") || url.ends_with("pipe.toml") {
+        vec![url.to_string()]
+    } else {
+        let base = url.trim_end_matches('/');
+        vec![format!("{}/pipe.json", base), format!("{}/pipe.toml", base)]
+    };
+
+    let mut last_err = None;
+    for candidate in candidates {
+        info!("Looking for pipe manifest at: {}", candidate);
+        match fetch_with_retry(client, &candidate, &[]).await {
+            Ok(response) => {
+                let raw = response.text().await?;
+                return parse_manifest_str(&candidate, &raw);
+            }
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| "no install manifest found".into()))
+}
+
+/// Fetch and parse a pipe's install manifest, building the `Pipeline` of
+/// steps needed to turn it into a runnable entry module under `work_dir`,
+/// along with whatever schedule/permissions the manifest declared. For an
+/// archive URL no manifest is read here: the pipeline only downloads and
+/// extracts the archive, and the metadata returned is empty.
+async fn build_pipeline(
+    client: &reqwest::Client,
+    url: &str,
+    work_dir: &Path,
+) -> Result<(Pipeline, ManifestInfo), Box<dyn std::error::Error>> {
+    if is_archive_url(url) {
+        let archive_name = Path::new(url)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("pipe.archive");
+        let archive_dest = PathBuf::from(archive_name);
+        let pipeline = Pipeline {
+            steps: vec![
+                Step::DownloadFile {
+                    url: url.to_string(),
+                    dest: archive_dest.clone(),
+                },
+                Step::ExtractFile {
+                    file: archive_dest,
+                    dest: PathBuf::from("."),
+                },
+            ],
+        };
+        return Ok((pipeline, ManifestInfo::default()));
+    }
+
+    let manifest = fetch_manifest(client, url).await?;
+    let manifest_info = ManifestInfo::from(&manifest);
+    std::fs::create_dir_all(work_dir)?;
+    Ok((manifest.into(), manifest_info))
+}
+
+async fn download_pipe(
+    client: &reqwest::Client,
+    url: &str,
+    allowed_hosts: &[String],
+    cache_dir: &Path,
+) -> Result<ResolvedPipe, Box<dyn std::error::Error>> {
     info!("Downloading pipe from URL: {}", url);
 
-    let raw_url = get_raw_github_url(url)?;
-    let parsed_url = Url::parse(&raw_url)?;
-    if parsed_url.host_str() != Some("raw.githubusercontent.com") {
-        error!("Only public GitHub URLs or raw.githubusercontent.com URLs are supported");
-        return Err(
-            "Only public GitHub URLs or raw.githubusercontent.com URLs are supported".into(),
-        );
+    if is_archive_url(url) || url.ends_with("pipe.json") || url.ends_with("pipe.toml") {
+        // Multi-step installs aren't single-file cache entries; they always
+        // re-run in a fresh temp directory, so a `DownloadFile` step only
+        // skips files already placed there earlier in the same run.
+        let work_dir = tempfile::tempdir()?.into_path();
+        let (pipeline, mut manifest) = build_pipeline(client, url, &work_dir).await?;
+        let mut entry = pipeline.run(client, &work_dir).await?;
+
+        if entry.is_none() {
+            // Archives don't declare their own entry up front: the manifest
+            // describing it only exists once extraction has happened.
+            if let Some(manifest_path) = find_extracted_manifest(&work_dir) {
+                let file_name = manifest_path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("pipe.json");
+                let raw = std::fs::read_to_string(&manifest_path)?;
+                let extracted_manifest = parse_manifest_str(file_name, &raw)?;
+                manifest = ManifestInfo::from(&extracted_manifest);
+                let manifest_dir = manifest_path.parent().unwrap_or(&work_dir);
+                let sub_pipeline: Pipeline = extracted_manifest.into();
+                entry = sub_pipeline.run(client, manifest_dir).await?;
+            }
+        }
+
+        let entry = entry.ok_or("pipe manifest did not declare an entry module")?;
+        return Ok(ResolvedPipe { entry, manifest });
+    }
+
+    let entry = download_single_file(client, url, allowed_hosts, cache_dir).await?;
+    Ok(ResolvedPipe {
+        entry,
+        manifest: ManifestInfo::default(),
+    })
+}
+
+/// Cached metadata for a single previously-downloaded pipe, enough to issue
+/// a conditional re-request and to locate the content-addressed bytes.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct CacheEntry {
+    source_url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    content_hash: String,
+    extension: String,
+}
+
+impl CacheEntry {
+    fn content_path(&self, cache_dir: &Path) -> PathBuf {
+        cache_dir
+            .join("objects")
+            .join(format!("{}.{}", self.content_hash, self.extension))
     }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+/// Resolve the pipe cache directory, creating it (and its `objects/`
+/// subdirectory) if needed. Defaults to the OS cache dir; overridable via
+/// `--pipe-cache-dir` for offline/air-gapped setups.
+fn resolve_cache_dir(pipe_cache_dir: &Option<PathBuf>) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let cache_dir = match pipe_cache_dir {
+        Some(dir) => dir.clone(),
+        None => dirs::cache_dir()
+            .ok_or("could not determine the OS cache directory")?
+            .join("screenpipe")
+            .join("pipes"),
+    };
+    std::fs::create_dir_all(cache_dir.join("objects"))?;
+    Ok(cache_dir)
+}
 
+fn cache_metadata_path(cache_dir: &Path, url: &str) -> PathBuf {
+    cache_dir.join(format!("{}.json", sha256_hex(url.as_bytes())))
+}
+
+fn read_cache_entry(meta_path: &Path) -> Option<CacheEntry> {
+    let bytes = std::fs::read(meta_path).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+async fn download_single_file(
+    client: &reqwest::Client,
+    url: &str,
+    allowed_hosts: &[String],
+    cache_dir: &Path,
+) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let raw_url = get_raw_url(url, allowed_hosts)?;
     info!("Downloading from raw URL: {}", raw_url);
     std::io::stdout().flush()?;
 
-    let response = match reqwest::get(&raw_url).await {
+    let meta_path = cache_metadata_path(cache_dir, &raw_url);
+    let cached = read_cache_entry(&meta_path);
+
+    let mut conditional_headers = Vec::new();
+    if let Some(entry) = &cached {
+        if let Some(etag) = &entry.etag {
+            conditional_headers.push((reqwest::header::IF_NONE_MATCH, etag.clone()));
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            conditional_headers.push((reqwest::header::IF_MODIFIED_SINCE, last_modified.clone()));
+        }
+    }
+
+    let response = match fetch_with_retry(client, &raw_url, &conditional_headers).await {
         Ok(resp) => resp,
         Err(e) => {
+            // No network, or the host is down: serve the cached copy rather
+            // than failing outright, so offline re-runs still work.
+            if let Some(entry) = &cached {
+                let content_path = entry.content_path(cache_dir);
+                if content_path.is_file() {
+                    warn!(
+                        "Failed to fetch {} ({}), serving cached copy: {:?}",
+                        raw_url, e, content_path
+                    );
+                    return Ok(content_path);
+                }
+            }
             error!("Failed to send GET request: {}", e);
-            return Err(e.into());
+            return Err(e);
         }
     };
 
-    let content = match response.text().await {
-        Ok(text) => text,
-        Err(e) => {
-            error!("Failed to get response text: {}", e);
-            return Err(e.into());
-        }
-    };
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+        let entry = cached.expect("server only returns 304 in response to a conditional request");
+        info!("already downloaded: {} (cache hit, server returned 304)", raw_url);
+        return Ok(entry.content_path(cache_dir));
+    }
 
-    info!("Downloaded content length: {} bytes", content.len());
+    let etag = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let last_modified = response
+        .headers()
+        .get(reqwest::header::LAST_MODIFIED)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
 
-    let mut temp_file = match NamedTempFile::new() {
-        Ok(file) => file,
+    let content = match response.bytes().await {
+        Ok(bytes) => bytes,
         Err(e) => {
-            error!("Failed to create temporary file: {}", e);
+            error!("Failed to get response body: {}", e);
             return Err(e.into());
         }
     };
 
-    if let Err(e) = temp_file.write_all(content.as_bytes()) {
-        error!("Failed to write content to temporary file: {}", e);
-        return Err(e.into());
-    }
+    info!("Downloaded content length: {} bytes", content.len());
 
     // Extract the file extension from the URL
     let extension = Path::new(url)
         .extension()
         .and_then(|ext| ext.to_str())
-        .unwrap_or("js"); // Default to .js if no extension is found
+        .unwrap_or("js") // Default to .js if no extension is found
+        .to_string();
 
-    info!("File extension: {}", extension);
+    let entry = CacheEntry {
+        source_url: raw_url,
+        etag,
+        last_modified,
+        content_hash: sha256_hex(&content),
+        extension,
+    };
 
-    // Create a new temporary file with the correct extension
-    let temp_path = temp_file.into_temp_path();
-    let final_path = temp_path.with_extension(extension);
-    if let Err(e) = std::fs::rename(&temp_path, &final_path) {
-        error!("Failed to rename temporary file: {}", e);
-        return Err(e.into());
+    let content_path = entry.content_path(cache_dir);
+    if content_path.exists() {
+        info!("Content already present in cache (dedup by hash): {:?}", content_path);
+    } else {
+        std::fs::write(&content_path, &content)?;
     }
+    std::fs::write(&meta_path, serde_json::to_vec_pretty(&entry)?)?;
 
-    info!("Pipe downloaded successfully to: {:?}", final_path);
+    info!("Pipe downloaded successfully to: {:?}", content_path);
 
-    Ok(final_path)
+    Ok(content_path)
 }
 
 #[cfg(not(feature = "pipes"))]
@@ -164,3 +978,266 @@ fn main() {
     eprintln!("Pipes support is not enabled. Compile with --features pipes to enable it.");
     std::process::exit(1);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_transient_status_covers_5xx_and_429() {
+        assert!(is_transient_status(reqwest::StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_transient_status(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(is_transient_status(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_transient_status(reqwest::StatusCode::NOT_FOUND));
+        assert!(!is_transient_status(reqwest::StatusCode::OK));
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially_without_retry_after() {
+        assert_eq!(retry_delay(1, None), Duration::from_millis(250));
+        assert_eq!(retry_delay(2, None), Duration::from_millis(500));
+        assert_eq!(retry_delay(3, None), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn retry_delay_honors_retry_after_when_present() {
+        assert_eq!(
+            retry_delay(3, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_seconds_form() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_http_date_form() {
+        let future = chrono::Utc::now() + chrono::Duration::seconds(60);
+        let header_value = future.to_rfc2822();
+        let parsed = parse_retry_after(&header_value).expect("should parse HTTP-date");
+        // Allow a little slack for the time spent formatting/parsing above.
+        assert!(parsed.as_secs() >= 55 && parsed.as_secs() <= 60);
+    }
+
+    #[test]
+    fn parse_retry_after_rejects_garbage() {
+        assert_eq!(parse_retry_after("not-a-valid-value"), None);
+    }
+
+    #[test]
+    fn normalize_cron_expr_prepends_seconds_to_5_field_crontab() {
+        assert_eq!(normalize_cron_expr("*/5 * * * *"), "0 */5 * * * *");
+    }
+
+    #[test]
+    fn normalize_cron_expr_leaves_6_field_expressions_unchanged() {
+        assert_eq!(normalize_cron_expr("0 */5 * * * *"), "0 */5 * * * *");
+    }
+
+    #[test]
+    fn normalize_cron_expr_leaves_malformed_input_unchanged() {
+        assert_eq!(normalize_cron_expr("not a schedule"), "not a schedule");
+    }
+
+    #[test]
+    fn resolve_redirect_url_joins_relative_locations() {
+        assert_eq!(
+            resolve_redirect_url("https://example.com/a/b", "c").as_deref(),
+            Some("https://example.com/a/c")
+        );
+    }
+
+    #[test]
+    fn resolve_redirect_url_passes_through_absolute_locations() {
+        assert_eq!(
+            resolve_redirect_url("https://example.com/a/b", "https://other.com/c").as_deref(),
+            Some("https://other.com/c")
+        );
+    }
+
+    #[test]
+    fn sha256_hex_matches_known_vector() {
+        // sha256("") - a standard test vector.
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn sha256_hex_is_deterministic_and_input_sensitive() {
+        assert_eq!(sha256_hex(b"hello"), sha256_hex(b"hello"));
+        assert_ne!(sha256_hex(b"hello"), sha256_hex(b"world"));
+    }
+
+    #[test]
+    fn cache_metadata_path_is_keyed_by_url_hash() {
+        let cache_dir = Path::new("/tmp/screenpipe-pipes");
+        let path = cache_metadata_path(cache_dir, "https://example.com/pipe.js");
+        assert_eq!(
+            path,
+            cache_dir.join(format!(
+                "{}.json",
+                sha256_hex(b"https://example.com/pipe.js")
+            ))
+        );
+    }
+
+    #[test]
+    fn cache_metadata_path_differs_for_different_urls() {
+        let cache_dir = Path::new("/tmp/screenpipe-pipes");
+        let a = cache_metadata_path(cache_dir, "https://example.com/a.js");
+        let b = cache_metadata_path(cache_dir, "https://example.com/b.js");
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_github_blob_pages() {
+        let raw = get_raw_url(
+            "https://github.com/owner/repo/blob/main/pipes/index.js",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            raw,
+            "https://raw.githubusercontent.com/owner/repo/main/pipes/index.js"
+        );
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_github_tree_pages() {
+        let raw = get_raw_url("https://github.com/owner/repo/tree/main/pipes", &[]).unwrap();
+        assert_eq!(
+            raw,
+            "https://raw.githubusercontent.com/owner/repo/main/pipes"
+        );
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_gist_pages() {
+        let raw = get_raw_url("https://gist.github.com/owner/abc123", &[]).unwrap();
+        assert_eq!(raw, "https://gist.githubusercontent.com/owner/abc123/raw");
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_gitlab_blob_pages() {
+        let raw = get_raw_url(
+            "https://gitlab.com/owner/repo/-/blob/main/pipes/index.js",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            raw,
+            "https://gitlab.com/owner/repo/-/raw/main/pipes/index.js"
+        );
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_self_hosted_gitlab_when_allowed() {
+        let raw = get_raw_url(
+            "https://gitlab.example.com/owner/repo/-/blob/main/index.js",
+            &["gitlab.example.com".to_string()],
+        )
+        .unwrap();
+        assert_eq!(
+            raw,
+            "https://gitlab.example.com/owner/repo/-/raw/main/index.js"
+        );
+    }
+
+    #[test]
+    fn get_raw_url_rewrites_bitbucket_src_pages() {
+        let raw = get_raw_url(
+            "https://bitbucket.org/owner/repo/src/main/index.js",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            raw,
+            "https://bitbucket.org/owner/repo/raw/main/index.js"
+        );
+    }
+
+    #[test]
+    fn get_raw_url_passes_through_unrecognized_hosts_unchanged() {
+        let raw = get_raw_url("https://example.com/pipes/index.js", &[]).unwrap();
+        assert_eq!(raw, "https://example.com/pipes/index.js");
+    }
+
+    #[test]
+    fn get_raw_url_passes_through_already_raw_urls_unchanged() {
+        let raw = get_raw_url(
+            "https://raw.githubusercontent.com/owner/repo/main/index.js",
+            &[],
+        )
+        .unwrap();
+        assert_eq!(
+            raw,
+            "https://raw.githubusercontent.com/owner/repo/main/index.js"
+        );
+    }
+
+    #[test]
+    fn github_raw_url_rejects_urls_without_enough_segments() {
+        assert!(github_raw_url(&["owner", "repo"]).is_err());
+    }
+
+    #[test]
+    fn github_raw_url_rejects_unsupported_kind() {
+        assert!(github_raw_url(&["owner", "repo", "commits", "main", "index.js"]).is_err());
+    }
+
+    #[test]
+    fn gitlab_raw_url_rejects_non_blob_paths() {
+        assert!(gitlab_raw_url("gitlab.com", "/owner/repo/-/tree/main").is_err());
+    }
+
+    #[test]
+    fn bitbucket_raw_url_rejects_non_src_paths() {
+        assert!(bitbucket_raw_url("bitbucket.org", "/owner/repo/commits/main").is_err());
+    }
+
+    #[test]
+    fn is_archive_url_recognizes_supported_extensions() {
+        assert!(is_archive_url("https://example.com/pipe.tar.gz"));
+        assert!(is_archive_url("https://example.com/pipe.tgz"));
+        assert!(is_archive_url("https://example.com/pipe.zip"));
+        assert!(!is_archive_url("https://example.com/pipe.js"));
+        assert!(!is_archive_url("https://example.com/pipe.json"));
+    }
+
+    #[test]
+    fn pipe_manifest_with_entry_only_becomes_a_single_run_js_step() {
+        let manifest = PipeManifest {
+            entry: Some(PathBuf::from("index.js")),
+            ..PipeManifest::default()
+        };
+        let pipeline: Pipeline = manifest.into();
+        assert_eq!(pipeline.steps.len(), 1);
+        assert!(matches!(&pipeline.steps[0], Step::RunJs { entry } if entry == Path::new("index.js")));
+    }
+
+    #[test]
+    fn pipe_manifest_appends_entry_after_declared_install_steps() {
+        let manifest = PipeManifest {
+            entry: Some(PathBuf::from("index.js")),
+            steps: vec![Step::ExtractFile {
+                file: PathBuf::from("archive.zip"),
+                dest: PathBuf::from("."),
+            }],
+            ..PipeManifest::default()
+        };
+        let pipeline: Pipeline = manifest.into();
+        assert_eq!(pipeline.steps.len(), 2);
+        assert!(matches!(&pipeline.steps[1], Step::RunJs { entry } if entry == Path::new("index.js")));
+    }
+
+    #[test]
+    fn pipe_manifest_with_no_entry_yields_no_run_js_step() {
+        let manifest = PipeManifest::default();
+        let pipeline: Pipeline = manifest.into();
+        assert!(pipeline.steps.is_empty());
+    }
+}